@@ -0,0 +1,3 @@
+//! Demo code shared between the various implot-rs example binaries.
+
+pub mod line_plots;
@@ -3,12 +3,13 @@
 
 use imgui::{CollapsingHeader, Condition, Ui};
 use implot::{
-    get_plot_limits, get_plot_mouse_position, is_legend_entry_hovered,
-    is_plot_hovered, pixels_to_plot_vec2, plot_to_pixels_vec2, push_style_color,
-    push_style_var_f32, push_style_var_i32,
-    AxisFlags, Colormap, ImPlotPoint, ImPlotRange, ImVec2, ImVec4,
-    Marker, Plot, PlotColorElement, PlotFlags, PlotLine, PlotLocation, PlotUi,
-    StyleVar, YAxisChoice,
+    get_colormap_color, get_input_map, get_plot_mouse_position, get_plot_query,
+    is_legend_entry_hovered, is_plot_hovered, is_plot_queried, pixels_to_plot_vec2,
+    plot_to_pixels_vec2, push_colormap, push_style_color, push_style_var_f32, push_style_var_i32,
+    set_colormap, set_input_map,
+    AxisFlags, Colormap, ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2, ImVec4, InputMap,
+    Marker, Plot, PlotBars, PlotColorElement, PlotDigital, PlotErrorBars, PlotFlags, PlotLine,
+    PlotScatter, PlotShaded, PlotUi, StyleVar, YAxisChoice,
 };
 
 use std::{cell::RefCell, rc::Rc};
@@ -150,8 +151,162 @@ impl LinePlotDemoState {
             });
     }
 
+    pub fn show_scatter_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to create a scatter plot.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Scatter plot")
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.4, 0.9, 0.2, 0.6, 0.1];
+                PlotScatter::new("legend label").plot(&x_positions, &y_positions);
+            });
+    }
+
+    pub fn show_shaded_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to create shaded/area plots.");
+        let content_width = ui.window_content_region_width();
+
+        let fill_style = push_style_color(&PlotColorElement::Fill, 0.0, 1.0, 0.0, 0.3);
+        Plot::new("Shaded plot")
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.4, 0.9, 0.2, 0.6, 0.1];
+                // Fills down to the default y_ref of 0.0.
+                PlotShaded::new("single series").plot(&x_positions, &y_positions);
+
+                let y_positions_upper = vec![2.4, 2.9, 2.2, 2.6, 2.1];
+                let y_positions_lower = vec![1.9, 2.1, 1.8, 2.0, 1.7];
+                // Fills the band between the two series instead.
+                PlotShaded::new("between two series").plot_between(
+                    &x_positions,
+                    &y_positions_upper,
+                    &y_positions_lower,
+                );
+            });
+        fill_style.pop();
+    }
+
+    pub fn show_bars_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to create a simple histogram-style bar chart.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Bar plot")
+            .size([content_width, 300.0])
+            .y_limits(
+                ImPlotRange { Min: 0.0, Max: 1.0 },
+                YAxisChoice::First,
+                Condition::Always,
+            )
+            .y_limits(
+                ImPlotRange { Min: 0.0, Max: 1.0 },
+                YAxisChoice::Second,
+                Condition::Always,
+            )
+            .build(plot_ui, || {
+                let x_positions = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+                let y_positions = vec![0.1, 0.6, 0.4, 0.8, 0.3];
+                PlotBars::new("vertical bars")
+                    .with_bar_width(0.5)
+                    .plot(&x_positions, &y_positions);
+
+                let y_positions_h = vec![0.2, 0.3, 0.5, 0.2, 0.7];
+                PlotBars::new("horizontal bars")
+                    .with_bar_width(0.5)
+                    .with_horizontal(true)
+                    .with_y_axis(YAxisChoice::Second)
+                    .plot(&x_positions, &y_positions_h);
+            });
+    }
+
+    pub fn show_error_bars_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to overlay error bars on a series.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Error bars plot")
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+                let y_positions = vec![0.4, 0.9, 0.2, 0.6, 0.1];
+
+                let error_bar_size = push_style_var_f32(&StyleVar::ErrorBarSize, 5.0);
+                let error_bar_weight = push_style_var_f32(&StyleVar::ErrorBarWeight, 1.5);
+
+                PlotScatter::new("measurements").plot(&x_positions, &y_positions);
+
+                // Symmetric error.
+                let err = vec![0.05, 0.1, 0.05, 0.08, 0.03];
+                PlotErrorBars::new("measurements").plot(&x_positions, &y_positions, &err);
+
+                // Asymmetric error - the uncertainty is larger on one side than the other.
+                let y_positions_asym = vec![1.4, 1.9, 1.2, 1.6, 1.1];
+                let err_neg = vec![0.02, 0.05, 0.02, 0.04, 0.01];
+                let err_pos = vec![0.1, 0.2, 0.1, 0.15, 0.08];
+                PlotScatter::new("asymmetric measurements")
+                    .plot(&x_positions, &y_positions_asym);
+                PlotErrorBars::new("asymmetric measurements").plot_asymmetric(
+                    &x_positions,
+                    &y_positions_asym,
+                    &err_neg,
+                    &err_pos,
+                );
+
+                error_bar_weight.pop();
+                error_bar_size.pop();
+            });
+    }
+
+    pub fn show_digital_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to plot digital/logic-signal channels.");
+        let content_width = ui.window_content_region_width();
+        Plot::new("Digital plot")
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let bit_height = push_style_var_i32(&StyleVar::DigitalBitHeight, 16);
+
+                let x_positions = vec![0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0];
+                let y_positions_a = vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0];
+                PlotDigital::new("channel A").plot(&x_positions, &y_positions_a);
+
+                let y_positions_b = vec![1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0];
+                PlotDigital::new("channel B").plot(&x_positions, &y_positions_b);
+
+                bit_height.pop();
+
+                // Unlike the pinned digital traces, a regular line scales with the Y axis and
+                // moves when the Y axis is zoomed.
+                PlotLine::new("analog reference").plot(&[0.0, 4.0], &[2.0, 3.0]);
+            });
+    }
+
+    pub fn show_input_map_plot(ui: &Ui, plot_ui: &PlotUi) {
+        ui.text("This header shows how to remap the mouse buttons used to interact with plots.");
+        let content_width = ui.window_content_region_width();
+
+        // The input map has to be changed before the plot that should be affected is built -
+        // there is no RAII push/pop for it like there is for styles, since it isn't meant to
+        // be toggled on a per-plot basis.
+        let previous_input_map: InputMap = get_input_map();
+        let mut input_map = previous_input_map;
+        // Swap panning onto the right mouse button, since the left one is used for box-select
+        // by the application embedding this plot.
+        input_map.pan = imgui::MouseButton::Right;
+        set_input_map(&input_map);
+
+        Plot::new("Remapped input plot")
+            .size([content_width, 300.0])
+            .build(plot_ui, || {
+                let x_positions = vec![0.1, 0.9];
+                let y_positions = vec![0.1, 0.9];
+                PlotLine::new("legend label").plot(&x_positions, &y_positions);
+            });
+
+        // Restore the map so other plots keep the defaults.
+        set_input_map(&previous_input_map);
+    }
+
     pub fn show_query_features_plot(ui: &Ui, plot_ui: &PlotUi) {
         ui.text("This header demos how to use the querying features.");
+        ui.text("Middle- or right-drag inside the plot to box-select a query region.");
         let content_width = ui.window_content_region_width();
 
         // Create some containers for exfiltrating data from the closure below
@@ -160,6 +315,7 @@ impl LinePlotDemoState {
         let mut hover_pos_from_pixels: Option<ImPlotPoint> = None;
         let mut legend1_hovered = false;
         let mut legend2_hovered = false;
+        let mut query_bounds: Option<ImPlotLimits> = None;
 
         // Draw a plot
         Plot::new("Plot querying")
@@ -170,6 +326,8 @@ impl LinePlotDemoState {
                 YAxisChoice::First,
                 Condition::FirstUseEver,
             )
+            // Lets the user box-select a region with the mouse, queryable below.
+            .with_plot_flags(&(PlotFlags::NONE | PlotFlags::QUERY))
             .build(plot_ui, || {
                 if is_plot_hovered() {
                     hover_pos_plot = Some(get_plot_mouse_position(None));
@@ -191,6 +349,10 @@ impl LinePlotDemoState {
                 PlotLine::new("Legend2").plot(&[0.0, 0.0], &[1.0, 1.0]);
                 legend1_hovered = is_legend_entry_hovered("Legend1");
                 legend2_hovered = is_legend_entry_hovered("Legend2");
+
+                if is_plot_queried() {
+                    query_bounds = Some(get_plot_query(None));
+                }
             });
 
         // Print some previously-exfiltrated info. This is because calling
@@ -220,6 +382,15 @@ impl LinePlotDemoState {
         if let Some(pos) = hover_pos_from_pixels {
             ui.text(format!("plot pos from imgui: {}, {}", pos.x, pos.y,));
         }
+
+        if let Some(bounds) = query_bounds {
+            ui.text(format!(
+                "query: x [{}, {}], y [{}, {}]",
+                bounds.X.Min, bounds.X.Max, bounds.Y.Min, bounds.Y.Max,
+            ));
+        } else {
+            ui.text("query: none yet");
+        }
     }
 
     pub fn show_style_plot(ui: &Ui, plot_ui: &PlotUi) {
@@ -270,15 +441,23 @@ impl LinePlotDemoState {
         ui.text("This header demos how to select colormaps.");
         let content_width = ui.window_content_region_width();
 
+        // Pushing a colormap works the same as pushing a style color or var - it returns a
+        // token on which we have to call .pop() to restore the previous map.
+        let colormap = push_colormap(Colormap::Viridis);
         Plot::new("Colormap demo plot")
             .size([content_width, 300.0])
             .build(plot_ui, || {
+                // Lines plotted without an explicit color cycle through the pushed map.
                 (1..10)
                     .map(|x| x as f64 * 0.1)
                     .map(|x| PlotLine::new(&format!("{:3.3}", x)).plot(&[0.1, 0.9], &[x, x]))
                     .count();
             });
+        colormap.pop();
 
+        // set_colormap is the non-RAII equivalent, useful when the change should persist
+        // beyond a single push/pop pair.
+        set_colormap(Colormap::Plasma);
         Plot::new("Colormap demo plot #2")
             .size([content_width, 300.0])
             .build(plot_ui, || {
@@ -288,6 +467,15 @@ impl LinePlotDemoState {
                     .count();
             });
 
+        // get_colormap_color lets callers build their own legend without relying on the
+        // order plots were drawn in.
+        let first_color: ImVec4 = get_colormap_color(0);
+        ui.text(format!(
+            "first color in current colormap: {}, {}, {}, {}",
+            first_color.x, first_color.y, first_color.z, first_color.w,
+        ));
+
+        set_colormap(Colormap::Standard);
     }
 
     pub fn show_conversions_plot(ui: &Ui, plot_ui: &PlotUi) {
@@ -333,6 +521,21 @@ impl LinePlotDemoState {
         if CollapsingHeader::new("Line plot: Configured").build(ui) {
             Self::show_configurable_plot(ui, plot_ui);
         }
+        if CollapsingHeader::new("Line plot: Scatter").build(ui) {
+            Self::show_scatter_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Shaded").build(ui) {
+            Self::show_shaded_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Bars").build(ui) {
+            Self::show_bars_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Error bars").build(ui) {
+            Self::show_error_bars_plot(ui, plot_ui);
+        }
+        if CollapsingHeader::new("Line plot: Digital").build(ui) {
+            Self::show_digital_plot(ui, plot_ui);
+        }
         if CollapsingHeader::new("Line Plot: Plot queries").build(ui) {
             Self::show_query_features_plot(ui, plot_ui);
         }
@@ -354,6 +557,9 @@ impl LinePlotDemoState {
         if CollapsingHeader::new("Line plot: Linked plots").build(ui) {
             self.show_linked_x_axis_plots(ui, plot_ui);
         }
+        if CollapsingHeader::new("Line plot: Input map").build(ui) {
+            Self::show_input_map_plot(ui, plot_ui);
+        }
     }
 }
 
@@ -0,0 +1,265 @@
+//! Raw FFI declarations for the subset of the cimplot/ImPlot C API that the
+//! safe `implot` crate wraps. These mirror the shapes cimplot exposes to C;
+//! no attempt is made to hide C conventions (pointers, raw flag ints) here -
+//! that's the job of the `implot` crate.
+
+#![allow(non_snake_case, non_camel_case_types)]
+
+use std::os::raw::{c_char, c_double, c_int};
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImVec2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImVec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImPlotPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImPlotRange {
+    pub Min: f64,
+    pub Max: f64,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImPlotLimits {
+    pub X: ImPlotRange,
+    pub Y: ImPlotRange,
+}
+
+/// Opaque handle to the global ImPlot context, created/destroyed exactly
+/// like an `ImGuiContext*`.
+#[repr(C)]
+pub struct ImPlotContext {
+    _private: [u8; 0],
+}
+
+/// Mirrors `ImPlotInputMap`: which mouse buttons/key modifiers drive panning, box-selection,
+/// context menus and zooming. Button fields hold an `ImGuiMouseButton`, `*Mod` fields an
+/// `ImGuiKeyModFlags`, both passed through as plain ints on the C side.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ImPlotInputMap {
+    pub Pan: c_int,
+    pub PanMod: c_int,
+    pub Fit: c_int,
+    pub Select: c_int,
+    pub SelectCancel: c_int,
+    pub SelectMod: c_int,
+    pub SelectHorzMod: c_int,
+    pub SelectVertMod: c_int,
+    pub Menu: c_int,
+    pub OverrideMod: c_int,
+    pub ZoomMod: c_int,
+    pub ZoomRate: f32,
+}
+
+pub type ImPlotFlags = c_int;
+pub type ImPlotAxisFlags = c_int;
+pub type ImPlotYAxis = c_int;
+pub type ImPlotCol = c_int;
+pub type ImPlotStyleVar = c_int;
+pub type ImPlotMarker = c_int;
+pub type ImPlotColormap = c_int;
+pub type ImPlotLocation = c_int;
+
+unsafe extern "C" {
+    pub fn ImPlot_CreateContext() -> *mut ImPlotContext;
+    pub fn ImPlot_DestroyContext(ctx: *mut ImPlotContext);
+    pub fn ImPlot_SetCurrentContext(ctx: *mut ImPlotContext);
+
+    pub fn ImPlot_BeginPlot(
+        title_id: *const c_char,
+        x_label: *const c_char,
+        y_label: *const c_char,
+        size: ImVec2,
+        flags: ImPlotFlags,
+        x_flags: ImPlotAxisFlags,
+        y_flags: ImPlotAxisFlags,
+        y2_flags: ImPlotAxisFlags,
+        y3_flags: ImPlotAxisFlags,
+    ) -> bool;
+    pub fn ImPlot_EndPlot();
+
+    pub fn ImPlot_SetNextPlotLimitsX(x_min: c_double, x_max: c_double, cond: c_int);
+    pub fn ImPlot_SetNextPlotLimitsY(
+        y_min: c_double,
+        y_max: c_double,
+        cond: c_int,
+        y_axis: ImPlotYAxis,
+    );
+    pub fn ImPlot_LinkNextPlotLimits(x_range: *mut ImPlotRange);
+
+    pub fn ImPlot_SetNextPlotTicksXdouble(
+        values: *const c_double,
+        n_ticks: c_int,
+        labels: *const *const c_char,
+        show_default: bool,
+    );
+    pub fn ImPlot_SetNextPlotTicksYdouble(
+        y_axis: ImPlotYAxis,
+        values: *const c_double,
+        n_ticks: c_int,
+        labels: *const *const c_char,
+        show_default: bool,
+    );
+
+    pub fn ImPlot_IsPlotHovered() -> bool;
+    pub fn ImPlot_IsPlotQueried() -> bool;
+    pub fn ImPlot_GetPlotQuery(y_axis: ImPlotYAxis) -> ImPlotLimits;
+    pub fn ImPlot_IsLegendEntryHovered(label_id: *const c_char) -> bool;
+    pub fn ImPlot_GetPlotMousePos(y_axis: ImPlotYAxis) -> ImPlotPoint;
+    pub fn ImPlot_GetPlotLimits(y_axis: ImPlotYAxis) -> ImPlotLimits;
+    pub fn ImPlot_PixelsToPlot(pix: ImVec2, y_axis: ImPlotYAxis) -> ImPlotPoint;
+    pub fn ImPlot_PlotToPixels(point: ImPlotPoint, y_axis: ImPlotYAxis) -> ImVec2;
+
+    pub fn ImPlot_PushStyleColor(idx: ImPlotCol, col: ImVec4);
+    pub fn ImPlot_PopStyleColor(count: c_int);
+    pub fn ImPlot_PushStyleVarFloat(idx: ImPlotStyleVar, val: f32);
+    pub fn ImPlot_PushStyleVarInt(idx: ImPlotStyleVar, val: c_int);
+    pub fn ImPlot_PopStyleVar(count: c_int);
+
+    /// Returns a pointer to the live input map owned by the current context - write through it
+    /// to change bindings, rather than copying the value out and back in.
+    pub fn ImPlot_GetInputMap() -> *mut ImPlotInputMap;
+
+    pub fn ImPlot_PushColormap(colormap: ImPlotColormap);
+    pub fn ImPlot_PopColormap(count: c_int);
+    /// `samples` of `0` means "use the colormap's own built-in sample count".
+    pub fn ImPlot_SetColormap(colormap: ImPlotColormap, samples: c_int);
+    pub fn ImPlot_GetColormapColor(idx: c_int) -> ImVec4;
+
+    pub fn ImPlot_PlotLine(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotScatter(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    /// Fills the region between the series and the horizontal line `y_ref`.
+    pub fn ImPlot_PlotShaded(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        y_ref: c_double,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    /// Fills the region between two series sharing the same `xs`.
+    pub fn ImPlot_PlotShadedTwoLines(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys1: *const c_double,
+        ys2: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    /// Selects which Y axis subsequent plot items are drawn against, mirroring how ImPlot
+    /// itself scopes Y-axis selection to "the rest of this plot" rather than per-item.
+    pub fn ImPlot_SetPlotYAxis(y_axis: ImPlotYAxis);
+
+    pub fn ImPlot_PlotBars(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        bar_width: c_double,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotBarsH(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        bar_height: c_double,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotErrorBars(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        err: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotErrorBarsAsymmetric(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        neg: *const c_double,
+        pos: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotErrorBarsH(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        err: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    pub fn ImPlot_PlotErrorBarsHAsymmetric(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        neg: *const c_double,
+        pos: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+
+    /// Renders `ys` (treated as boolean, 0.0/1.0) as a digital logic trace pinned to a fixed
+    /// pixel height near the bottom of the plot, rather than scaling with the Y axis.
+    pub fn ImPlot_PlotDigital(
+        label_id: *const c_char,
+        xs: *const c_double,
+        ys: *const c_double,
+        count: c_int,
+        offset: c_int,
+        stride: c_int,
+    );
+}
@@ -0,0 +1,35 @@
+use crate::sys;
+
+/// Owns the ImPlot context, similarly to how `imgui::Context` owns the ImGui context. Create
+/// exactly one of these alongside your `imgui::Context` before drawing any plots.
+pub struct Context {
+    raw: *mut sys::ImPlotContext,
+}
+
+impl Context {
+    /// Create a new ImPlot context and make it the active one.
+    pub fn create() -> Self {
+        let raw = unsafe { sys::ImPlot_CreateContext() };
+        unsafe { sys::ImPlot_SetCurrentContext(raw) };
+        Self { raw }
+    }
+
+    /// Get a `PlotUi`, which is required to call any of the plotting functions. Mirrors how
+    /// `imgui::Context::frame()` hands out a `Ui` for widget calls.
+    pub fn get_plot_ui(&self) -> PlotUi<'_> {
+        unsafe { sys::ImPlot_SetCurrentContext(self.raw) };
+        PlotUi { _context: self }
+    }
+}
+
+impl Drop for Context {
+    fn drop(&mut self) {
+        unsafe { sys::ImPlot_DestroyContext(self.raw) };
+    }
+}
+
+/// Proof that an ImPlot context is active. A reference to this is required by `Plot::build`; the
+/// plot items and querying functions are called inside its callback and take no `PlotUi`.
+pub struct PlotUi<'ui> {
+    _context: &'ui Context,
+}
@@ -0,0 +1,50 @@
+use bitflags::bitflags;
+use implot_sys as sys;
+
+bitflags! {
+    /// Flags for customizing plot behavior and interaction, to be passed into
+    /// `Plot::with_plot_flags`. Bit values taken from implot.h in cimplot.
+    pub struct PlotFlags: sys::ImPlotFlags {
+        const NONE = 0;
+        const NO_LEGEND = 1 << 0;
+        const NO_MENUS = 1 << 1;
+        const NO_BOX_SELECT = 1 << 2;
+        const NO_MOUSE_TEXT = 1 << 3;
+        const NO_HIGHLIGHT = 1 << 4;
+        const NO_CHILD = 1 << 5;
+        const Y_AXIS_2 = 1 << 6;
+        const Y_AXIS_3 = 1 << 7;
+        const CROSSHAIRS = 1 << 8;
+        const ANTIALIASED = 1 << 9;
+        const AXIS_EQUAL = 1 << 10;
+        /// Enables a query rectangle that the user can middle- or right-drag to select a
+        /// region with, readable via `is_plot_queried`/`get_plot_query`.
+        const QUERY = 1 << 11;
+        const CANVAS_ONLY = Self::NO_LEGEND.bits
+            | Self::NO_MENUS.bits
+            | Self::NO_BOX_SELECT.bits
+            | Self::NO_MOUSE_TEXT.bits;
+    }
+}
+
+bitflags! {
+    /// Flags for customizing axis behavior, to be passed into `Plot::with_x_axis_flags` and
+    /// `Plot::with_y_axis_flags`.
+    pub struct AxisFlags: sys::ImPlotAxisFlags {
+        const NONE = 0;
+        const NO_LABEL = 1 << 0;
+        const NO_GRID_LINES = 1 << 1;
+        const NO_TICK_MARKS = 1 << 2;
+        const NO_TICK_LABELS = 1 << 3;
+        const LOG_SCALE = 1 << 4;
+        const TIME = 1 << 5;
+        const INVERT = 1 << 6;
+        const LOCK_MIN = 1 << 7;
+        const LOCK_MAX = 1 << 8;
+        const LOCK = Self::LOCK_MIN.bits | Self::LOCK_MAX.bits;
+        const NO_DECORATIONS = Self::NO_LABEL.bits
+            | Self::NO_GRID_LINES.bits
+            | Self::NO_TICK_MARKS.bits
+            | Self::NO_TICK_LABELS.bits;
+    }
+}
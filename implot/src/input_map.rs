@@ -0,0 +1,81 @@
+use imgui::MouseButton;
+use implot_sys as sys;
+use sys::ImPlotInputMap;
+
+fn mouse_button_from_i32(value: i32) -> MouseButton {
+    match value {
+        0 => MouseButton::Left,
+        1 => MouseButton::Right,
+        2 => MouseButton::Middle,
+        3 => MouseButton::Extra1,
+        4 => MouseButton::Extra2,
+        _ => panic!("Unexpected ImGuiMouseButton value: {}", value),
+    }
+}
+
+/// Which mouse buttons and key modifiers drive panning, box-selection, the context menu and
+/// zooming. Read the active bindings with `get_input_map`, tweak them and write them back with
+/// `set_input_map`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InputMap {
+    pub pan: MouseButton,
+    pub pan_mod: i32,
+    pub fit: MouseButton,
+    pub select: MouseButton,
+    pub select_cancel: MouseButton,
+    pub select_mod: i32,
+    pub select_horz_mod: i32,
+    pub select_vert_mod: i32,
+    pub menu: MouseButton,
+    pub override_mod: i32,
+    pub zoom_mod: i32,
+    pub zoom_rate: f32,
+}
+
+impl From<ImPlotInputMap> for InputMap {
+    fn from(raw: ImPlotInputMap) -> Self {
+        Self {
+            pan: mouse_button_from_i32(raw.Pan),
+            pan_mod: raw.PanMod,
+            fit: mouse_button_from_i32(raw.Fit),
+            select: mouse_button_from_i32(raw.Select),
+            select_cancel: mouse_button_from_i32(raw.SelectCancel),
+            select_mod: raw.SelectMod,
+            select_horz_mod: raw.SelectHorzMod,
+            select_vert_mod: raw.SelectVertMod,
+            menu: mouse_button_from_i32(raw.Menu),
+            override_mod: raw.OverrideMod,
+            zoom_mod: raw.ZoomMod,
+            zoom_rate: raw.ZoomRate,
+        }
+    }
+}
+
+impl From<InputMap> for ImPlotInputMap {
+    fn from(map: InputMap) -> Self {
+        ImPlotInputMap {
+            Pan: map.pan as i32,
+            PanMod: map.pan_mod,
+            Fit: map.fit as i32,
+            Select: map.select as i32,
+            SelectCancel: map.select_cancel as i32,
+            SelectMod: map.select_mod,
+            SelectHorzMod: map.select_horz_mod,
+            SelectVertMod: map.select_vert_mod,
+            Menu: map.menu as i32,
+            OverrideMod: map.override_mod,
+            ZoomMod: map.zoom_mod,
+            ZoomRate: map.zoom_rate,
+        }
+    }
+}
+
+/// The input bindings currently in effect.
+pub fn get_input_map() -> InputMap {
+    unsafe { (*sys::ImPlot_GetInputMap()).into() }
+}
+
+/// Replace the input bindings currently in effect.
+pub fn set_input_map(input_map: &InputMap) {
+    unsafe { *sys::ImPlot_GetInputMap() = (*input_map).into() };
+}
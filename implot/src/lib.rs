@@ -0,0 +1,27 @@
+//! Idiomatic Rust bindings for [ImPlot](https://github.com/epezent/implot), built on top of
+//! the raw `implot-sys` FFI layer and meant to be used alongside `imgui-rs`.
+
+pub use implot_sys as sys;
+pub use sys::{ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2, ImVec4};
+
+mod context;
+mod flags;
+mod input_map;
+mod plot;
+mod plot_elements;
+mod style;
+
+pub use context::{Context, PlotUi};
+pub use flags::{AxisFlags, PlotFlags};
+pub use input_map::{get_input_map, set_input_map, InputMap};
+pub use plot::{
+    get_plot_limits, get_plot_mouse_position, get_plot_query, is_legend_entry_hovered,
+    is_plot_hovered, is_plot_queried, pixels_to_plot_vec2, plot_to_pixels_vec2, Plot,
+    PlotLocation, YAxisChoice,
+};
+pub use plot_elements::{PlotBars, PlotDigital, PlotErrorBars, PlotLine, PlotScatter, PlotShaded};
+pub use style::{
+    get_colormap_color, push_colormap, push_style_color, push_style_var_f32, push_style_var_i32,
+    set_colormap, Colormap, ColormapToken, Marker, PlotColorElement, StyleColorToken, StyleVar,
+    StyleVarToken,
+};
@@ -0,0 +1,348 @@
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+use std::rc::Rc;
+
+use imgui::Condition;
+use implot_sys as sys;
+use sys::{ImPlotLimits, ImPlotPoint, ImPlotRange, ImVec2};
+
+use crate::context::PlotUi;
+use crate::flags::{AxisFlags, PlotFlags};
+
+/// Which Y axis a plot item or axis-related setting applies to. ImPlot supports up to three.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YAxisChoice {
+    First = 0,
+    Second = 1,
+    Third = 2,
+}
+
+impl YAxisChoice {
+    pub(crate) fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    /// The `y_axis` parameter most functions here take is an `Option`; `None` is passed to ImPlot
+    /// as the first Y axis (index 0).
+    pub(crate) fn option_to_i32(y_axis: Option<Self>) -> i32 {
+        y_axis.map(Self::as_i32).unwrap_or(0)
+    }
+}
+
+/// Corner/edge locations, used for things like legend placement.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlotLocation {
+    Center = 0,
+    North = 1,
+    South = 2,
+    West = 3,
+    East = 4,
+    NorthWest = 5,
+    NorthEast = 6,
+    SouthWest = 7,
+    SouthEast = 8,
+}
+
+/// Anything that can be turned into the `Min`/`Max` pair ImPlot uses for axis limits. Implemented
+/// for `ImPlotRange` itself as well as the various convenience types the demos use.
+pub trait PlotRangeValue {
+    fn into_plot_range(self) -> ImPlotRange;
+}
+
+impl PlotRangeValue for ImPlotRange {
+    fn into_plot_range(self) -> ImPlotRange {
+        self
+    }
+}
+
+impl PlotRangeValue for ImVec2 {
+    fn into_plot_range(self) -> ImPlotRange {
+        ImPlotRange {
+            Min: self.x as f64,
+            Max: self.y as f64,
+        }
+    }
+}
+
+impl PlotRangeValue for [f32; 2] {
+    fn into_plot_range(self) -> ImPlotRange {
+        ImPlotRange {
+            Min: self[0] as f64,
+            Max: self[1] as f64,
+        }
+    }
+}
+
+impl PlotRangeValue for [f64; 2] {
+    fn into_plot_range(self) -> ImPlotRange {
+        ImPlotRange {
+            Min: self[0],
+            Max: self[1],
+        }
+    }
+}
+
+impl PlotRangeValue for (f32, f32) {
+    fn into_plot_range(self) -> ImPlotRange {
+        ImPlotRange {
+            Min: self.0 as f64,
+            Max: self.1 as f64,
+        }
+    }
+}
+
+impl PlotRangeValue for (f64, f64) {
+    fn into_plot_range(self) -> ImPlotRange {
+        ImPlotRange {
+            Min: self.0,
+            Max: self.1,
+        }
+    }
+}
+
+/// Values/labels for one Y axis' ticks, plus whether the automatic ticks should still be shown.
+type YTickLabels = (Vec<f64>, Vec<String>, bool);
+
+/// Builder for a single plot, following the same "configure, then build" pattern as imgui-rs'
+/// window and widget builders.
+pub struct Plot {
+    title: String,
+    size: [f32; 2],
+    x_label: String,
+    y_label: String,
+    x_limits: Option<(ImPlotRange, Condition)>,
+    y_limits: [Option<(ImPlotRange, Condition)>; 3],
+    x_ticks: Option<(Vec<f64>, bool)>,
+    y_ticks: [Option<YTickLabels>; 3],
+    plot_flags: PlotFlags,
+    x_axis_flags: AxisFlags,
+    y_axis_flags: [AxisFlags; 3],
+    linked_x_limits: Option<Rc<RefCell<ImPlotRange>>>,
+}
+
+impl Plot {
+    /// Create a new plot builder with the given legend/title label.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_owned(),
+            size: [0.0, 0.0],
+            x_label: String::new(),
+            y_label: String::new(),
+            x_limits: None,
+            y_limits: [None, None, None],
+            x_ticks: None,
+            y_ticks: [None, None, None],
+            plot_flags: PlotFlags::NONE,
+            x_axis_flags: AxisFlags::NONE,
+            y_axis_flags: [AxisFlags::NONE, AxisFlags::NONE, AxisFlags::NONE],
+            linked_x_limits: None,
+        }
+    }
+
+    pub fn size(mut self, size: [f32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn x_label(mut self, label: &str) -> Self {
+        self.x_label = label.to_owned();
+        self
+    }
+
+    pub fn y_label(mut self, label: &str) -> Self {
+        self.y_label = label.to_owned();
+        self
+    }
+
+    pub fn x_limits<L: PlotRangeValue>(mut self, limits: L, condition: Condition) -> Self {
+        self.x_limits = Some((limits.into_plot_range(), condition));
+        self
+    }
+
+    pub fn y_limits<L: PlotRangeValue>(
+        mut self,
+        limits: L,
+        y_axis: YAxisChoice,
+        condition: Condition,
+    ) -> Self {
+        self.y_limits[y_axis as usize] = Some((limits.into_plot_range(), condition));
+        self
+    }
+
+    /// Unlabelled X axis ticks - the given values are shown in addition to the automatic ones
+    /// unless `show_default` is false.
+    pub fn x_ticks(mut self, ticks: &[f64], show_default: bool) -> Self {
+        self.x_ticks = Some((ticks.to_vec(), show_default));
+        self
+    }
+
+    /// Labelled Y axis ticks for the given axis.
+    pub fn y_ticks_with_labels(
+        mut self,
+        y_axis: YAxisChoice,
+        ticks: &[(f64, String)],
+        show_default: bool,
+    ) -> Self {
+        let values = ticks.iter().map(|(value, _)| *value).collect();
+        let labels = ticks.iter().map(|(_, label)| label.clone()).collect();
+        self.y_ticks[y_axis as usize] = Some((values, labels, show_default));
+        self
+    }
+
+    pub fn with_plot_flags(mut self, flags: &PlotFlags) -> Self {
+        self.plot_flags = *flags;
+        self
+    }
+
+    pub fn with_x_axis_flags(mut self, flags: &AxisFlags) -> Self {
+        self.x_axis_flags = *flags;
+        self
+    }
+
+    pub fn with_y_axis_flags(mut self, y_axis: YAxisChoice, flags: &AxisFlags) -> Self {
+        self.y_axis_flags[y_axis as usize] = *flags;
+        self
+    }
+
+    /// Link this plot's X axis limits to those of another plot sharing the same `Rc<RefCell<_>>`,
+    /// so that panning/zooming one moves the other.
+    pub fn linked_x_limits(mut self, limits: Rc<RefCell<ImPlotRange>>) -> Self {
+        self.linked_x_limits = Some(limits);
+        self
+    }
+
+    /// Draw the plot, calling `build_fn` to add plot items if ImPlot decides the plot is
+    /// actually visible. If this is called outside of a frame, the program will panic (the same
+    /// contract as `imgui-rs` window building).
+    pub fn build<F: FnOnce()>(self, _plot_ui: &PlotUi, build_fn: F) {
+        if let Some(limits) = &self.linked_x_limits {
+            unsafe { sys::ImPlot_LinkNextPlotLimits(limits.as_ptr()) };
+        }
+
+        if let Some((range, condition)) = self.x_limits {
+            unsafe { sys::ImPlot_SetNextPlotLimitsX(range.Min, range.Max, condition as i32) };
+        }
+
+        for (axis_index, entry) in self.y_limits.iter().enumerate() {
+            if let Some((range, condition)) = entry {
+                unsafe {
+                    sys::ImPlot_SetNextPlotLimitsY(
+                        range.Min,
+                        range.Max,
+                        *condition as i32,
+                        axis_index as i32,
+                    )
+                };
+            }
+        }
+
+        if let Some((values, show_default)) = &self.x_ticks {
+            unsafe {
+                sys::ImPlot_SetNextPlotTicksXdouble(
+                    values.as_ptr(),
+                    values.len() as i32,
+                    ptr::null(),
+                    *show_default,
+                )
+            };
+        }
+
+        // Kept alive until after the BeginPlot call below, which reads the label pointers.
+        let mut y_tick_label_storage: Vec<Vec<CString>> = Vec::new();
+        for (axis_index, entry) in self.y_ticks.iter().enumerate() {
+            if let Some((values, labels, show_default)) = entry {
+                let c_labels: Vec<CString> = labels
+                    .iter()
+                    .map(|label| CString::new(label.as_str()).unwrap())
+                    .collect();
+                let label_ptrs: Vec<*const c_char> =
+                    c_labels.iter().map(|label| label.as_ptr()).collect();
+                unsafe {
+                    sys::ImPlot_SetNextPlotTicksYdouble(
+                        axis_index as i32,
+                        values.as_ptr(),
+                        values.len() as i32,
+                        label_ptrs.as_ptr(),
+                        *show_default,
+                    )
+                };
+                y_tick_label_storage.push(c_labels);
+            }
+        }
+
+        let title = CString::new(self.title).unwrap();
+        let x_label = CString::new(self.x_label).unwrap();
+        let y_label = CString::new(self.y_label).unwrap();
+
+        let should_render = unsafe {
+            sys::ImPlot_BeginPlot(
+                title.as_ptr(),
+                x_label.as_ptr(),
+                y_label.as_ptr(),
+                ImVec2 {
+                    x: self.size[0],
+                    y: self.size[1],
+                },
+                self.plot_flags.bits(),
+                self.x_axis_flags.bits(),
+                self.y_axis_flags[0].bits(),
+                self.y_axis_flags[1].bits(),
+                self.y_axis_flags[2].bits(),
+            )
+        };
+
+        if should_render {
+            build_fn();
+        }
+
+        unsafe { sys::ImPlot_EndPlot() };
+    }
+}
+
+/// Whether the current plot is hovered by the mouse. Must be called inside a `Plot::build`
+/// callback.
+pub fn is_plot_hovered() -> bool {
+    unsafe { sys::ImPlot_IsPlotHovered() }
+}
+
+/// Whether the user has box-selected a query region in the current plot. Only meaningful if the
+/// plot was built with `PlotFlags::QUERY`. Must be called inside a `Plot::build` callback.
+pub fn is_plot_queried() -> bool {
+    unsafe { sys::ImPlot_IsPlotQueried() }
+}
+
+/// The bounds of the current query region. See `is_plot_queried`.
+pub fn get_plot_query(y_axis: Option<YAxisChoice>) -> ImPlotLimits {
+    unsafe { sys::ImPlot_GetPlotQuery(YAxisChoice::option_to_i32(y_axis)) }
+}
+
+/// Whether the given legend entry is hovered by the mouse. Must be called inside a
+/// `Plot::build` callback.
+pub fn is_legend_entry_hovered(label_id: &str) -> bool {
+    let label_id = CString::new(label_id).unwrap();
+    unsafe { sys::ImPlot_IsLegendEntryHovered(label_id.as_ptr()) }
+}
+
+/// The mouse position in plot coordinates. Must be called inside a `Plot::build` callback.
+pub fn get_plot_mouse_position(y_axis: Option<YAxisChoice>) -> ImPlotPoint {
+    unsafe { sys::ImPlot_GetPlotMousePos(YAxisChoice::option_to_i32(y_axis)) }
+}
+
+/// The limits of the current plot. Must be called inside a `Plot::build` callback.
+pub fn get_plot_limits(y_axis: Option<YAxisChoice>) -> ImPlotLimits {
+    unsafe { sys::ImPlot_GetPlotLimits(YAxisChoice::option_to_i32(y_axis)) }
+}
+
+/// Convert a pixel position (as reported by imgui, for instance) into a plot position. Unlike
+/// most other functions here, this also works outside a hovered/active plot - the returned
+/// position is then simply outside the visible range.
+pub fn pixels_to_plot_vec2(pixel_position: &ImVec2, y_axis: Option<YAxisChoice>) -> ImPlotPoint {
+    unsafe { sys::ImPlot_PixelsToPlot(*pixel_position, YAxisChoice::option_to_i32(y_axis)) }
+}
+
+/// The inverse of `pixels_to_plot_vec2`.
+pub fn plot_to_pixels_vec2(plot_position: &ImPlotPoint, y_axis: Option<YAxisChoice>) -> ImVec2 {
+    unsafe { sys::ImPlot_PlotToPixels(*plot_position, YAxisChoice::option_to_i32(y_axis)) }
+}
@@ -0,0 +1,308 @@
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use implot_sys as sys;
+
+use crate::plot::YAxisChoice;
+
+/// Generate the common boilerplate for a plot item that is nothing more than a label plus an
+/// x/y data series, dispatching straight into the matching sys function.
+macro_rules! impl_simple_plot_item {
+    ($name:ident, $sys_fn:path, $doc:expr) => {
+        #[doc = $doc]
+        pub struct $name {
+            label: CString,
+        }
+
+        impl $name {
+            pub fn new(label: &str) -> Self {
+                Self {
+                    label: CString::new(label).unwrap(),
+                }
+            }
+
+            /// Plot the given x/y data series. `x` and `y` must have the same length.
+            pub fn plot(&self, x: &[f64], y: &[f64]) {
+                assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+                unsafe {
+                    $sys_fn(
+                        self.label.as_ptr() as *const c_char,
+                        x.as_ptr(),
+                        y.as_ptr(),
+                        x.len() as i32,
+                        0,
+                        std::mem::size_of::<f64>() as i32,
+                    );
+                }
+            }
+        }
+    };
+}
+
+impl_simple_plot_item!(
+    PlotLine,
+    sys::ImPlot_PlotLine,
+    "A plain line/point series, drawn with connecting line segments between points."
+);
+
+impl_simple_plot_item!(
+    PlotDigital,
+    sys::ImPlot_PlotDigital,
+    "A boolean/logic-signal channel, pinned to a fixed pixel height regardless of Y zoom. Set \
+     the per-channel height with `StyleVar::DigitalBitHeight`, exactly like `Marker` is set for \
+     scatter plots."
+);
+
+/// A scatter/marker-only series - the same data a `PlotLine` would draw, but rendered as
+/// disconnected markers instead of connected line segments.
+pub struct PlotScatter {
+    label: CString,
+}
+
+impl PlotScatter {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label).unwrap(),
+        }
+    }
+
+    /// Plot the given x/y data series as markers. `x` and `y` must have the same length.
+    ///
+    /// Respects the `Marker`/`MarkerSize`/`MarkerWeight` style vars pushed via
+    /// `push_style_var_i32`/`push_style_var_f32`. If the global marker style is currently
+    /// `Marker::None`, ImPlot falls back to a visible `Marker::Circle` itself, so a scatter plot
+    /// is never invisible by default.
+    pub fn plot(&self, x: &[f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+        unsafe {
+            sys::ImPlot_PlotScatter(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                x.len() as i32,
+                0,
+                std::mem::size_of::<f64>() as i32,
+            );
+        }
+    }
+}
+
+/// A filled/area series. Either fills down to a reference Y level (see `plot`), or fills the
+/// band between two series sharing the same X values (see `plot_between`). Honors the
+/// `PlotColorElement::Fill` style color and the `StyleVar::FillAlpha` style var, the same way
+/// `push_style_color`/`push_style_var_f32` are used for other items.
+pub struct PlotShaded {
+    label: CString,
+    y_ref: f64,
+}
+
+impl PlotShaded {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label).unwrap(),
+            y_ref: 0.0,
+        }
+    }
+
+    /// Sets the Y level that `plot` fills down (or up) to. Defaults to `0.0`.
+    pub fn with_y_ref(mut self, y_ref: f64) -> Self {
+        self.y_ref = y_ref;
+        self
+    }
+
+    /// Fill the region between the series and `y_ref` (`0.0` unless overridden with
+    /// `with_y_ref`). `x` and `y` must have the same length.
+    pub fn plot(&self, x: &[f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+        unsafe {
+            sys::ImPlot_PlotShaded(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y.as_ptr(),
+                x.len() as i32,
+                self.y_ref,
+                0,
+                std::mem::size_of::<f64>() as i32,
+            );
+        }
+    }
+
+    /// Fill the region between two Y series sharing the same `x`. `x`, `y1` and `y2` must all
+    /// have the same length.
+    pub fn plot_between(&self, x: &[f64], y1: &[f64], y2: &[f64]) {
+        assert_eq!(x.len(), y1.len(), "x and y1 data must have the same length");
+        assert_eq!(x.len(), y2.len(), "x and y2 data must have the same length");
+        unsafe {
+            sys::ImPlot_PlotShadedTwoLines(
+                self.label.as_ptr() as *const c_char,
+                x.as_ptr(),
+                y1.as_ptr(),
+                y2.as_ptr(),
+                x.len() as i32,
+                0,
+                std::mem::size_of::<f64>() as i32,
+            );
+        }
+    }
+}
+
+/// A vertical (default) or horizontal bar series. Interoperates with the multi-Y-axis support
+/// on `Plot` - assign a bar series to a non-default axis with `with_y_axis`.
+pub struct PlotBars {
+    label: CString,
+    bar_width: f64,
+    horizontal: bool,
+    y_axis: Option<YAxisChoice>,
+}
+
+impl PlotBars {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label).unwrap(),
+            // Matches ImPlot's own default bar width.
+            bar_width: 0.67,
+            horizontal: false,
+            y_axis: None,
+        }
+    }
+
+    /// Bar width, in plot-coordinate (not pixel) units.
+    pub fn with_bar_width(mut self, bar_width: f64) -> Self {
+        self.bar_width = bar_width;
+        self
+    }
+
+    /// When set, bars are drawn horizontally (dispatching to `ImPlot_PlotBarsH`) and
+    /// `bar_width` is interpreted as a bar height instead.
+    pub fn with_horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Assigns this series to a non-default Y axis.
+    pub fn with_y_axis(mut self, y_axis: YAxisChoice) -> Self {
+        self.y_axis = Some(y_axis);
+        self
+    }
+
+    /// Plot the given x/y data series as bars. `x` and `y` must have the same length.
+    pub fn plot(&self, x: &[f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+        if let Some(y_axis) = self.y_axis {
+            unsafe { sys::ImPlot_SetPlotYAxis(y_axis.as_i32()) };
+        }
+        unsafe {
+            if self.horizontal {
+                sys::ImPlot_PlotBarsH(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    x.len() as i32,
+                    self.bar_width,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            } else {
+                sys::ImPlot_PlotBars(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    x.len() as i32,
+                    self.bar_width,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            }
+        }
+        // ImPlot_SetPlotYAxis selects the axis for the rest of the plot, not just this item -
+        // reset back to the default so a following item isn't silently drawn against this axis.
+        if self.y_axis.is_some() {
+            unsafe { sys::ImPlot_SetPlotYAxis(YAxisChoice::option_to_i32(None)) };
+        }
+    }
+}
+
+/// Error bar overlays for a data series, typically drawn over a `PlotLine` or `PlotScatter`
+/// sharing the same `x`/`y`. Respects the `StyleVar::ErrorBarSize`/`ErrorBarWeight` style vars.
+pub struct PlotErrorBars {
+    label: CString,
+    horizontal: bool,
+}
+
+impl PlotErrorBars {
+    pub fn new(label: &str) -> Self {
+        Self {
+            label: CString::new(label).unwrap(),
+            horizontal: false,
+        }
+    }
+
+    /// When set, dispatches to the horizontal (`*H*`) sys functions instead.
+    pub fn with_horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Plot symmetric error bars of size `err` around each `(x, y)` point. All three slices must
+    /// have the same length.
+    pub fn plot(&self, x: &[f64], y: &[f64], err: &[f64]) {
+        assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+        assert_eq!(x.len(), err.len(), "x and err data must have the same length");
+        unsafe {
+            if self.horizontal {
+                sys::ImPlot_PlotErrorBarsH(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    err.as_ptr(),
+                    x.len() as i32,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            } else {
+                sys::ImPlot_PlotErrorBars(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    err.as_ptr(),
+                    x.len() as i32,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            }
+        }
+    }
+
+    /// Plot asymmetric error bars, with separate negative and positive extents around each
+    /// `(x, y)` point. All four slices must have the same length.
+    pub fn plot_asymmetric(&self, x: &[f64], y: &[f64], neg: &[f64], pos: &[f64]) {
+        assert_eq!(x.len(), y.len(), "x and y data must have the same length");
+        assert_eq!(x.len(), neg.len(), "x and neg data must have the same length");
+        assert_eq!(x.len(), pos.len(), "x and pos data must have the same length");
+        unsafe {
+            if self.horizontal {
+                sys::ImPlot_PlotErrorBarsHAsymmetric(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    neg.as_ptr(),
+                    pos.as_ptr(),
+                    x.len() as i32,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            } else {
+                sys::ImPlot_PlotErrorBarsAsymmetric(
+                    self.label.as_ptr() as *const c_char,
+                    x.as_ptr(),
+                    y.as_ptr(),
+                    neg.as_ptr(),
+                    pos.as_ptr(),
+                    x.len() as i32,
+                    0,
+                    std::mem::size_of::<f64>() as i32,
+                );
+            }
+        }
+    }
+}
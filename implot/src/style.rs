@@ -0,0 +1,191 @@
+use implot_sys as sys;
+use sys::ImVec4;
+
+/// Colorable plot elements, to be used with `push_style_color`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlotColorElement {
+    Line = 0,
+    Fill = 1,
+    MarkerOutline = 2,
+    MarkerFill = 3,
+    ErrorBar = 4,
+    FrameBg = 5,
+    PlotBg = 6,
+    PlotBorder = 7,
+    LegendBg = 8,
+    LegendBorder = 9,
+    LegendText = 10,
+    TitleText = 11,
+    InlayText = 12,
+    XAxis = 13,
+    YAxis = 14,
+    XAxisGrid = 15,
+    YAxisGrid = 16,
+}
+
+/// Style variables that can be pushed with `push_style_var_f32`/`push_style_var_i32`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StyleVar {
+    LineWeight = 0,
+    Marker = 1,
+    MarkerSize = 2,
+    MarkerWeight = 3,
+    FillAlpha = 4,
+    PlotBorderSize = 5,
+    MinorAlpha = 6,
+    ErrorBarSize = 7,
+    ErrorBarWeight = 8,
+    DigitalBitHeight = 9,
+}
+
+/// Marker shapes, to be used as the value pushed for `StyleVar::Marker`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Marker {
+    None = -1,
+    Circle = 0,
+    Square = 1,
+    Diamond = 2,
+    Up = 3,
+    Down = 4,
+    Left = 5,
+    Right = 6,
+    Cross = 7,
+    Plus = 8,
+    Asterisk = 9,
+}
+
+/// Token returned by `push_style_color`. Call `.pop()` to undo the change, the same way
+/// `imgui`'s style color tokens work.
+#[must_use]
+pub struct StyleColorToken {
+    ended: bool,
+}
+
+impl StyleColorToken {
+    pub fn pop(mut self) {
+        self.ended = true;
+        unsafe { sys::ImPlot_PopStyleColor(1) };
+    }
+}
+
+impl Drop for StyleColorToken {
+    fn drop(&mut self) {
+        if !self.ended {
+            panic!("StyleColorToken was not popped, but was dropped.");
+        }
+    }
+}
+
+/// Push a plot style color onto the style stack. Returns a token on which `.pop()` must be
+/// called to undo the change.
+pub fn push_style_color(
+    element: &PlotColorElement,
+    red: f32,
+    green: f32,
+    blue: f32,
+    alpha: f32,
+) -> StyleColorToken {
+    unsafe {
+        sys::ImPlot_PushStyleColor(
+            *element as i32,
+            ImVec4 {
+                x: red,
+                y: green,
+                z: blue,
+                w: alpha,
+            },
+        );
+    }
+    StyleColorToken { ended: false }
+}
+
+/// Token returned by `push_style_var_f32`/`push_style_var_i32`. Call `.pop()` to undo the
+/// change.
+#[must_use]
+pub struct StyleVarToken {
+    ended: bool,
+}
+
+impl StyleVarToken {
+    pub fn pop(mut self) {
+        self.ended = true;
+        unsafe { sys::ImPlot_PopStyleVar(1) };
+    }
+}
+
+impl Drop for StyleVarToken {
+    fn drop(&mut self) {
+        if !self.ended {
+            panic!("StyleVarToken was not popped, but was dropped.");
+        }
+    }
+}
+
+/// Push a `f32`-valued style variable onto the style stack.
+pub fn push_style_var_f32(style_var: &StyleVar, value: f32) -> StyleVarToken {
+    unsafe { sys::ImPlot_PushStyleVarFloat(*style_var as i32, value) };
+    StyleVarToken { ended: false }
+}
+
+/// Push an `i32`-valued style variable onto the style stack. Markers are represented as an i32
+/// internally, hence this is the one to use for `StyleVar::Marker`.
+pub fn push_style_var_i32(style_var: &StyleVar, value: i32) -> StyleVarToken {
+    unsafe { sys::ImPlot_PushStyleVarInt(*style_var as i32, value) };
+    StyleVarToken { ended: false }
+}
+
+/// Built-in colormaps, selectable with `push_colormap`/`set_colormap`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Colormap {
+    Standard = 0,
+    Dark = 1,
+    Pastel = 2,
+    Paired = 3,
+    Viridis = 4,
+    Plasma = 5,
+    Hot = 6,
+    Cool = 7,
+    Pink = 8,
+    Jet = 9,
+}
+
+/// Token returned by `push_colormap`. Call `.pop()` to restore the previously active colormap.
+#[must_use]
+pub struct ColormapToken {
+    ended: bool,
+}
+
+impl ColormapToken {
+    pub fn pop(mut self) {
+        self.ended = true;
+        unsafe { sys::ImPlot_PopColormap(1) };
+    }
+}
+
+impl Drop for ColormapToken {
+    fn drop(&mut self) {
+        if !self.ended {
+            panic!("ColormapToken was not popped, but was dropped.");
+        }
+    }
+}
+
+/// Push a colormap onto the colormap stack for the colors ImPlot automatically cycles through
+/// on successive series (`PlotLine`, `PlotScatter`, ...). Returns a token on which `.pop()` must
+/// be called to restore the previous map.
+pub fn push_colormap(colormap: Colormap) -> ColormapToken {
+    unsafe { sys::ImPlot_PushColormap(colormap as i32) };
+    ColormapToken { ended: false }
+}
+
+/// Set the active colormap without pushing/popping it - use this when the change should persist
+/// rather than being scoped to a single plot.
+pub fn set_colormap(colormap: Colormap) {
+    unsafe { sys::ImPlot_SetColormap(colormap as i32, 0) };
+}
+
+/// Look up an individual color in the currently active colormap, for building a caller-owned
+/// legend instead of relying on plot draw order.
+pub fn get_colormap_color(index: i32) -> ImVec4 {
+    unsafe { sys::ImPlot_GetColormapColor(index) }
+}